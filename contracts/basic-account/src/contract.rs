@@ -41,6 +41,38 @@ impl BasicAccountContract {
             &policies,
         );
     }
+
+    /// Execute a batch of calls on target contracts in a single authorized
+    /// transaction.
+    ///
+    /// Requires a single smart account authorization covering the whole
+    /// batch. Soroban transactions are atomic, so if any call in the batch
+    /// fails, the entire batch (and any state it touched) is reverted. This
+    /// avoids multiple round trips for flows like approving a token and then
+    /// depositing into a lending protocol in one step.
+    ///
+    /// This is an inherent method rather than part of the
+    /// `ExecutionEntryPoint` impl below: that trait is owned by the
+    /// external `stellar_accounts` crate and only declares `execute`, so it
+    /// can't be extended with a new method from this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - Vector of `(target, target_fn, target_args)` tuples to
+    ///   invoke in order
+    ///
+    /// # Returns
+    ///
+    /// Vector of each call's result, in the same order as `calls`.
+    pub fn execute_batch(e: &Env, calls: Vec<(Address, Symbol, Vec<Val>)>) -> Vec<Val> {
+        e.current_contract_address().require_auth();
+
+        let mut results = Vec::new(e);
+        for (target, target_fn, target_args) in calls.iter() {
+            results.push_back(e.invoke_contract::<Val>(&target, &target_fn, target_args));
+        }
+        results
+    }
 }
 
 #[contractimpl]
@@ -0,0 +1,182 @@
+//! # Rate Limit Policy Contract
+//!
+//! A reusable policy contract that throttles how frequently a context rule
+//! can authorize a given target operation, by requiring a minimum interval
+//! between successive invocations of the same `(target, function)` pair.
+//! This brings the time-based-restriction technique (tracking the last
+//! invocation time and rejecting calls inside a cooldown) into the policy
+//! framework, so accounts can declaratively rate-limit sensitive actions
+//! (e.g. one withdrawal per hour) without forking `do_check_auth`.
+use soroban_sdk::{auth::Context, contract, contractimpl, contracttype, Address, Env, Symbol, Vec};
+use stellar_accounts::{
+    policies::Policy,
+    smart_account::{ContextRule, Signer},
+};
+
+/// A minimum interval, in seconds, that must elapse between calls to
+/// `function` on `target`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RateLimit {
+    pub target: Address,
+    pub function: Symbol,
+    pub interval: u64,
+}
+
+/// Parameters used to install the rate limit policy for a context rule.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RateLimitAccountParams {
+    pub limits: Vec<RateLimit>,
+}
+
+#[contracttype]
+enum DataKey {
+    Limit(Address, u32, Address, Symbol),
+    LastInvocation(Address, u32, Address, Symbol),
+}
+
+#[contract]
+pub struct RateLimitPolicyContract;
+
+#[contractimpl]
+impl Policy for RateLimitPolicyContract {
+    type AccountParams = RateLimitAccountParams;
+
+    /// Check if the rate limit policy can be enforced.
+    ///
+    /// When the invoked `(target, function)` has a configured interval,
+    /// returns `false` unless at least `interval` seconds have passed since
+    /// the last invocation. Calls with no configured interval are left
+    /// unrestricted.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the cooldown has elapsed (or no limit is configured)
+    /// * `false` if the call is still within its cooldown
+    fn can_enforce(
+        e: &Env,
+        context: Context,
+        _authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        let Context::Contract(call) = context else {
+            return true;
+        };
+
+        let limit_key = DataKey::Limit(
+            smart_account.clone(),
+            context_rule.id,
+            call.contract.clone(),
+            call.fn_name.clone(),
+        );
+        let Some(interval) = e.storage().persistent().get::<_, u64>(&limit_key) else {
+            return true;
+        };
+
+        let last_key =
+            DataKey::LastInvocation(smart_account, context_rule.id, call.contract, call.fn_name);
+        let last = e
+            .storage()
+            .persistent()
+            .get::<_, u64>(&last_key)
+            .unwrap_or(0);
+
+        e.ledger().timestamp().saturating_sub(last) >= interval
+    }
+
+    /// Enforce the rate limit policy.
+    ///
+    /// Records the current ledger timestamp as the last invocation time for
+    /// the invoked `(target, function)` pair, but only when that pair has a
+    /// configured interval — otherwise there is nothing to rate-limit, and
+    /// writing an entry would bloat persistent storage for every call
+    /// routed through the policy.
+    fn enforce(
+        e: &Env,
+        context: Context,
+        _authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        let Context::Contract(call) = context else {
+            return;
+        };
+
+        let limit_key = DataKey::Limit(
+            smart_account.clone(),
+            context_rule.id,
+            call.contract.clone(),
+            call.fn_name.clone(),
+        );
+        if e.storage().persistent().get::<_, u64>(&limit_key).is_none() {
+            return;
+        }
+
+        let last_key =
+            DataKey::LastInvocation(smart_account, context_rule.id, call.contract, call.fn_name);
+        e.storage()
+            .persistent()
+            .set(&last_key, &e.ledger().timestamp());
+    }
+
+    /// Install the rate limit policy for a smart account.
+    ///
+    /// Stores the configured minimum interval for each `(target, function)`
+    /// pair under the given context rule.
+    fn install(
+        e: &Env,
+        install_params: Self::AccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        for limit in install_params.limits.iter() {
+            let key = DataKey::Limit(
+                smart_account.clone(),
+                context_rule.id,
+                limit.target,
+                limit.function,
+            );
+            e.storage().persistent().set(&key, &limit.interval);
+        }
+    }
+
+    /// Uninstall the rate limit policy for a smart account.
+    ///
+    /// Limits are keyed by individual `(target, function)` pairs, so they
+    /// must be removed one at a time via
+    /// [`RateLimitPolicyContract::remove_limit`]; this is a no-op hook for
+    /// the [`Policy`] lifecycle.
+    fn uninstall(_e: &Env, _context_rule: ContextRule, _smart_account: Address) {}
+}
+
+#[contractimpl]
+impl RateLimitPolicyContract {
+    /// Get the configured interval, in seconds, for a `(target, function)`
+    /// pair under a context rule, if any.
+    pub fn get_interval(
+        e: &Env,
+        context_rule_id: u32,
+        smart_account: Address,
+        target: Address,
+        function: Symbol,
+    ) -> Option<u64> {
+        let key = DataKey::Limit(smart_account, context_rule_id, target, function);
+        e.storage().persistent().get(&key)
+    }
+
+    /// Remove a configured `(target, function)` rate limit.
+    pub fn remove_limit(
+        e: &Env,
+        context_rule_id: u32,
+        smart_account: Address,
+        target: Address,
+        function: Symbol,
+    ) {
+        smart_account.require_auth();
+
+        let key = DataKey::Limit(smart_account, context_rule_id, target, function);
+        e.storage().persistent().remove(&key);
+    }
+}
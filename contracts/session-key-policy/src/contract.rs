@@ -0,0 +1,281 @@
+//! # Session Key Policy Contract
+//!
+//! A reusable policy contract that lets a smart account register ephemeral
+//! "session" signers: hot keys that may only authorize a restricted
+//! allowlist of `(target, function)` calls for a bounded period, optionally
+//! capped by a per-call and cumulative spend limit. This mirrors the
+//! session-key model used by ERC-4337 smart wallets, so automation (e.g. a
+//! bot that monitors and rebalances DeFi positions) can act with narrow,
+//! time-boxed, revocable authority instead of the master signer.
+use soroban_sdk::{
+    auth::{Context, ContractContext},
+    contract, contractimpl, contracttype, Address, Env, Symbol, TryFromVal, Val, Vec,
+};
+use stellar_accounts::{
+    policies::Policy,
+    smart_account::{ContextRule, Signer},
+};
+
+/// A single `(target_address, function_symbol)` pair a session key may invoke.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionCall {
+    pub target: Address,
+    pub function: Symbol,
+}
+
+/// Parameters used to register a session key for a context rule.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SessionKeyAccountParams {
+    pub session_signer: Signer,
+    pub valid_after: u64,
+    pub valid_until: u64,
+    pub allowed_calls: Vec<SessionCall>,
+    pub per_call_limit: Option<i128>,
+    pub spend_limit: Option<i128>,
+}
+
+/// On-chain state tracked for a single registered session key.
+#[contracttype]
+#[derive(Clone, Debug)]
+struct SessionKeyData {
+    valid_after: u64,
+    valid_until: u64,
+    allowed_calls: Vec<SessionCall>,
+    per_call_limit: Option<i128>,
+    spend_remaining: Option<i128>,
+}
+
+#[contracttype]
+enum DataKey {
+    Session(Address, u32, Signer),
+}
+
+/// The session that actually authorizes a call, found by
+/// [`SessionKeyPolicyContract::matching_session`], along with the decoded
+/// call amount (if one was needed to check a spend cap).
+struct SessionMatch {
+    signer: Signer,
+    session: SessionKeyData,
+    amount: Option<i128>,
+}
+
+#[contract]
+pub struct SessionKeyPolicyContract;
+
+impl SessionKeyPolicyContract {
+    /// Extracts the invoked `amount` argument (conventionally the third
+    /// argument of a token-transfer-shaped call) so spend caps can be
+    /// enforced without the policy knowing the target's full ABI.
+    fn call_amount(e: &Env, args: &Vec<Val>) -> Option<i128> {
+        args.get(2).and_then(|val| i128::try_from_val(e, &val).ok())
+    }
+
+    /// Finds the first authenticated signer with a registered, non-expired
+    /// session key whose allowlist covers the invoked call and whose limits
+    /// (if any) the call satisfies. Expired sessions are pruned as a side
+    /// effect. Used identically by `can_enforce` and `enforce` so the
+    /// signer that is checked is provably the one that is later debited.
+    ///
+    /// A configured `per_call_limit` or `spend_remaining` cap fails closed:
+    /// if the call's amount can't be decoded, the session does not match
+    /// rather than being treated as a zero-value call.
+    fn matching_session(
+        e: &Env,
+        call: &ContractContext,
+        authenticated_signers: &Vec<Signer>,
+        context_rule_id: u32,
+        smart_account: &Address,
+    ) -> Option<SessionMatch> {
+        let now = e.ledger().timestamp();
+
+        for signer in authenticated_signers.iter() {
+            let key = DataKey::Session(smart_account.clone(), context_rule_id, signer.clone());
+            let Some(session) = e.storage().persistent().get::<_, SessionKeyData>(&key) else {
+                continue;
+            };
+
+            if now < session.valid_after {
+                // Not yet active: skip without pruning, so a session
+                // provisioned in advance survives until its window opens.
+                continue;
+            }
+            if now >= session.valid_until {
+                e.storage().persistent().remove(&key);
+                continue;
+            }
+
+            let allowed = session
+                .allowed_calls
+                .iter()
+                .any(|c| c.target == call.contract && c.function == call.fn_name);
+            if !allowed {
+                continue;
+            }
+
+            let needs_amount =
+                session.per_call_limit.is_some() || session.spend_remaining.is_some();
+            let amount = Self::call_amount(e, &call.args);
+            if needs_amount && amount.is_none() {
+                continue;
+            }
+
+            if let Some(limit) = session.per_call_limit {
+                if amount.unwrap() > limit {
+                    continue;
+                }
+            }
+
+            if let Some(remaining) = session.spend_remaining {
+                if amount.unwrap() > remaining {
+                    continue;
+                }
+            }
+
+            return Some(SessionMatch {
+                signer,
+                session,
+                amount,
+            });
+        }
+
+        None
+    }
+}
+
+#[contractimpl]
+impl Policy for SessionKeyPolicyContract {
+    type AccountParams = SessionKeyAccountParams;
+
+    /// Check if a session key policy can be enforced.
+    ///
+    /// Finds an authenticated signer that matches a registered, non-expired
+    /// session key whose allowlist covers the invoked `(contract, fn_name)`
+    /// and, when a spend cap is configured, whose remaining budget covers
+    /// the call's amount.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if a matching, valid session key authorizes this call
+    /// * `false` otherwise
+    fn can_enforce(
+        e: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        let Context::Contract(call) = context else {
+            return false;
+        };
+
+        Self::matching_session(
+            e,
+            &call,
+            &authenticated_signers,
+            context_rule.id,
+            &smart_account,
+        )
+        .is_some()
+    }
+
+    /// Enforce the session key policy.
+    ///
+    /// Decrements the remaining cumulative spend (if configured) for the
+    /// matching session key and emits an event. Sessions are pruned lazily:
+    /// an expired session is removed the next time it is looked up. Uses
+    /// the same matching logic as `can_enforce`, so the session that is
+    /// debited is guaranteed to be the one that authorized the call.
+    fn enforce(
+        e: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        let Context::Contract(call) = context else {
+            return;
+        };
+
+        let Some(SessionMatch {
+            signer,
+            mut session,
+            amount,
+        }) = Self::matching_session(
+            e,
+            &call,
+            &authenticated_signers,
+            context_rule.id,
+            &smart_account,
+        )
+        else {
+            return;
+        };
+
+        if let Some(remaining) = session.spend_remaining {
+            session.spend_remaining = Some(remaining.saturating_sub(amount.unwrap()));
+        }
+
+        let key = DataKey::Session(smart_account.clone(), context_rule.id, signer.clone());
+        e.storage().persistent().set(&key, &session);
+        e.events().publish(
+            (
+                Symbol::new(e, "session_used"),
+                smart_account,
+                context_rule.id,
+            ),
+            signer,
+        );
+    }
+
+    /// Install the session key policy for a smart account.
+    ///
+    /// Registers the session signer's validity window, call allowlist and
+    /// optional spend caps for the given context rule.
+    fn install(
+        e: &Env,
+        install_params: Self::AccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        let key = DataKey::Session(
+            smart_account,
+            context_rule.id,
+            install_params.session_signer,
+        );
+        let session = SessionKeyData {
+            valid_after: install_params.valid_after,
+            valid_until: install_params.valid_until,
+            allowed_calls: install_params.allowed_calls,
+            per_call_limit: install_params.per_call_limit,
+            spend_remaining: install_params.spend_limit,
+        };
+        e.storage().persistent().set(&key, &session);
+    }
+
+    /// Uninstall the session key policy for a smart account.
+    ///
+    /// Session records are keyed by individual `session_signer`, so they
+    /// cannot be enumerated and swept here; callers should revoke each
+    /// session via [`SessionKeyPolicyContract::revoke_session`] before (or
+    /// after) removing the policy from the context rule.
+    fn uninstall(_e: &Env, _context_rule: ContextRule, _smart_account: Address) {}
+}
+
+#[contractimpl]
+impl SessionKeyPolicyContract {
+    /// Revoke a previously registered session key, e.g. if the hot key is
+    /// compromised before it naturally expires.
+    pub fn revoke_session(
+        e: &Env,
+        context_rule_id: u32,
+        smart_account: Address,
+        session_signer: Signer,
+    ) {
+        smart_account.require_auth();
+
+        let key = DataKey::Session(smart_account, context_rule_id, session_signer);
+        e.storage().persistent().remove(&key);
+    }
+}
@@ -0,0 +1,202 @@
+//! # Spending Limit Policy Contract
+//!
+//! A reusable policy contract that caps the cumulative value a context rule
+//! may authorize within a sliding time window, per invoked token contract.
+//! This lets accounts bound risk on automated flows (e.g. a bot that
+//! rebalances but may never move more than X per day), a capability the
+//! uniform M-of-N `simple_threshold` policy cannot express.
+use soroban_sdk::{
+    auth::Context, contract, contractimpl, contracttype, Address, Env, TryFromVal, Val, Vec,
+};
+use stellar_accounts::{
+    policies::Policy,
+    smart_account::{ContextRule, Signer},
+};
+
+/// The allowance and window configured for a single token.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SpendingLimit {
+    pub token: Address,
+    pub allowance: i128,
+    pub window: u64,
+}
+
+/// Parameters used to install the spending limit policy for a context rule.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SpendingLimitAccountParams {
+    pub limits: Vec<SpendingLimit>,
+}
+
+/// Rolling-window spend tracking for a single token.
+#[contracttype]
+#[derive(Clone, Debug)]
+struct SpendingWindow {
+    allowance: i128,
+    window: u64,
+    spent_in_window: i128,
+    window_start: u64,
+}
+
+#[contracttype]
+enum DataKey {
+    Window(Address, u32, Address),
+}
+
+#[contract]
+pub struct SpendingLimitPolicyContract;
+
+impl SpendingLimitPolicyContract {
+    /// Extracts the invoked `amount` argument (conventionally the third
+    /// argument of a token-transfer-shaped call). Returns `None` if the
+    /// call has no third argument or it isn't an `i128`, so that a
+    /// configured limit fails closed instead of treating an unparseable
+    /// call as moving zero value.
+    fn call_amount(e: &Env, args: &Vec<Val>) -> Option<i128> {
+        args.get(2).and_then(|val| i128::try_from_val(e, &val).ok())
+    }
+
+    /// Rolls `window` forward if it has fully elapsed, resetting the spent
+    /// total, and returns the effective window state for `now`.
+    fn rolled_window(window: &SpendingWindow, now: u64) -> SpendingWindow {
+        if now.saturating_sub(window.window_start) >= window.window {
+            SpendingWindow {
+                allowance: window.allowance,
+                window: window.window,
+                spent_in_window: 0,
+                window_start: now,
+            }
+        } else {
+            window.clone()
+        }
+    }
+}
+
+#[contractimpl]
+impl Policy for SpendingLimitPolicyContract {
+    type AccountParams = SpendingLimitAccountParams;
+
+    /// Check if the spending limit policy can be enforced.
+    ///
+    /// Rolls the spend window forward if it has fully elapsed, then returns
+    /// `true` only if the invoked amount plus what was already spent in the
+    /// current window stays within the configured allowance.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the call fits within the remaining window allowance (or
+    ///   no limit is configured for the invoked token)
+    /// * `false` if the call would exceed the allowance, or a limit is
+    ///   configured but the call's amount can't be decoded
+    fn can_enforce(
+        e: &Env,
+        context: Context,
+        _authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        let Context::Contract(call) = context else {
+            return true;
+        };
+
+        let key = DataKey::Window(smart_account, context_rule.id, call.contract);
+        let Some(window) = e.storage().persistent().get::<_, SpendingWindow>(&key) else {
+            return true;
+        };
+
+        let Some(amount) = Self::call_amount(e, &call.args) else {
+            return false;
+        };
+
+        let now = e.ledger().timestamp();
+        let window = Self::rolled_window(&window, now);
+
+        window.spent_in_window + amount <= window.allowance
+    }
+
+    /// Enforce the spending limit policy.
+    ///
+    /// Commits the rolled-forward window state with the invoked amount
+    /// added to `spent_in_window`.
+    fn enforce(
+        e: &Env,
+        context: Context,
+        _authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        let Context::Contract(call) = context else {
+            return;
+        };
+
+        let key = DataKey::Window(smart_account, context_rule.id, call.contract);
+        let Some(window) = e.storage().persistent().get::<_, SpendingWindow>(&key) else {
+            return;
+        };
+
+        let Some(amount) = Self::call_amount(e, &call.args) else {
+            return;
+        };
+
+        let now = e.ledger().timestamp();
+        let mut window = Self::rolled_window(&window, now);
+        window.spent_in_window += amount;
+
+        e.storage().persistent().set(&key, &window);
+    }
+
+    /// Install the spending limit policy for a smart account.
+    ///
+    /// Stores the configured allowance and window for each token under the
+    /// given context rule.
+    fn install(
+        e: &Env,
+        install_params: Self::AccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        for limit in install_params.limits.iter() {
+            let key = DataKey::Window(smart_account.clone(), context_rule.id, limit.token);
+            let window = SpendingWindow {
+                allowance: limit.allowance,
+                window: limit.window,
+                spent_in_window: 0,
+                window_start: e.ledger().timestamp(),
+            };
+            e.storage().persistent().set(&key, &window);
+        }
+    }
+
+    /// Uninstall the spending limit policy for a smart account.
+    ///
+    /// Windows are keyed by individual tokens, so they must be removed one
+    /// at a time via [`SpendingLimitPolicyContract::remove_limit`]; this is
+    /// a no-op hook for the [`Policy`] lifecycle.
+    fn uninstall(_e: &Env, _context_rule: ContextRule, _smart_account: Address) {}
+}
+
+#[contractimpl]
+impl SpendingLimitPolicyContract {
+    /// Get the remaining allowance in the current window for a token, if
+    /// configured.
+    pub fn get_remaining(
+        e: &Env,
+        context_rule_id: u32,
+        smart_account: Address,
+        token: Address,
+    ) -> Option<i128> {
+        let key = DataKey::Window(smart_account, context_rule_id, token);
+        let window = e.storage().persistent().get::<_, SpendingWindow>(&key)?;
+        let window = Self::rolled_window(&window, e.ledger().timestamp());
+        Some(window.allowance - window.spent_in_window)
+    }
+
+    /// Remove a configured token spending limit.
+    pub fn remove_limit(e: &Env, context_rule_id: u32, smart_account: Address, token: Address) {
+        smart_account.require_auth();
+
+        let key = DataKey::Window(smart_account, context_rule_id, token);
+        e.storage().persistent().remove(&key);
+    }
+}
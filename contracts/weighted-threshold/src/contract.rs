@@ -0,0 +1,180 @@
+//! # Weighted Threshold Policy Contract
+//!
+//! A reusable policy contract, sibling to `ThresholdPolicyContract`, that
+//! supports per-signer vote weights instead of uniform one-signer-one-vote
+//! counting. Each signer on a context rule carries an individual `u32`
+//! weight; authorization succeeds once the weights of the authenticated
+//! signers meet or exceed the configured threshold. This supports
+//! governance layouts like "2 admins (weight 2 each) OR 3 operators (weight
+//! 1 each)" that the uniform M-of-N counting in `simple_threshold` cannot
+//! represent.
+use soroban_sdk::{auth::Context, contract, contractimpl, contracttype, Address, Env, Map, Vec};
+use stellar_accounts::{
+    policies::Policy,
+    smart_account::{ContextRule, Signer},
+};
+
+/// Parameters used to install the weighted threshold policy for a context
+/// rule: each signer's weight and the threshold the weights must meet.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct WeightedThresholdAccountParams {
+    pub weights: Map<Signer, u32>,
+    pub threshold: u32,
+}
+
+#[contracttype]
+enum DataKey {
+    Weights(Address, u32),
+    Threshold(Address, u32),
+}
+
+#[contract]
+pub struct WeightedThresholdPolicyContract;
+
+#[contractimpl]
+impl Policy for WeightedThresholdPolicyContract {
+    type AccountParams = WeightedThresholdAccountParams;
+
+    /// Check if the weighted threshold policy can be enforced.
+    ///
+    /// Sums the configured weight of each authenticated signer and returns
+    /// `true` only if the sum meets or exceeds the configured threshold. A
+    /// context rule with no threshold installed is not yet configured and
+    /// fails closed rather than being treated as an always-met `0`
+    /// threshold.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if a threshold is installed and the summed weight meets it
+    /// * `false` otherwise
+    fn can_enforce(
+        e: &Env,
+        _context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        let Some(threshold) = Self::threshold(e, context_rule.id, smart_account.clone()) else {
+            return false;
+        };
+        let weights = Self::weights(e, context_rule.id, smart_account);
+
+        let total: u32 = authenticated_signers
+            .iter()
+            .map(|signer| weights.get(signer).unwrap_or(0))
+            .sum();
+
+        total >= threshold
+    }
+
+    /// Enforce the weighted threshold policy.
+    ///
+    /// The weighted threshold check in [`can_enforce`](Self::can_enforce)
+    /// is stateless, so there is nothing further to record.
+    fn enforce(
+        _e: &Env,
+        _context: Context,
+        _authenticated_signers: Vec<Signer>,
+        _context_rule: ContextRule,
+        _smart_account: Address,
+    ) {
+    }
+
+    /// Install the weighted threshold policy for a smart account.
+    ///
+    /// Stores the per-signer weights and the threshold for the given
+    /// context rule.
+    fn install(
+        e: &Env,
+        install_params: Self::AccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        let weights_key = DataKey::Weights(smart_account.clone(), context_rule.id);
+        let threshold_key = DataKey::Threshold(smart_account, context_rule.id);
+
+        e.storage()
+            .persistent()
+            .set(&weights_key, &install_params.weights);
+        e.storage()
+            .persistent()
+            .set(&threshold_key, &install_params.threshold);
+    }
+
+    /// Uninstall the weighted threshold policy for a smart account.
+    ///
+    /// Removes the weight map and threshold configuration for the given
+    /// context rule.
+    fn uninstall(e: &Env, context_rule: ContextRule, smart_account: Address) {
+        let weights_key = DataKey::Weights(smart_account.clone(), context_rule.id);
+        let threshold_key = DataKey::Threshold(smart_account, context_rule.id);
+
+        e.storage().persistent().remove(&weights_key);
+        e.storage().persistent().remove(&threshold_key);
+    }
+}
+
+#[contractimpl]
+impl WeightedThresholdPolicyContract {
+    /// Get the current threshold for a smart account, or `0` if the policy
+    /// has not been installed for this context rule.
+    pub fn get_threshold(e: &Env, context_rule_id: u32, smart_account: Address) -> u32 {
+        Self::threshold(e, context_rule_id, smart_account).unwrap_or(0)
+    }
+
+    /// Set a new threshold for a smart account.
+    ///
+    /// Requires smart account authorization.
+    pub fn set_threshold(e: Env, threshold: u32, context_rule_id: u32, smart_account: Address) {
+        smart_account.require_auth();
+
+        let key = DataKey::Threshold(smart_account, context_rule_id);
+        e.storage().persistent().set(&key, &threshold);
+    }
+
+    /// Get a signer's current weight for a smart account.
+    pub fn get_weight(
+        e: &Env,
+        context_rule_id: u32,
+        smart_account: Address,
+        signer: Signer,
+    ) -> u32 {
+        Self::weights(e, context_rule_id, smart_account)
+            .get(signer)
+            .unwrap_or(0)
+    }
+
+    /// Set a signer's weight for a smart account.
+    ///
+    /// Requires smart account authorization.
+    pub fn set_weight(
+        e: Env,
+        context_rule_id: u32,
+        smart_account: Address,
+        signer: Signer,
+        weight: u32,
+    ) {
+        smart_account.require_auth();
+
+        let key = DataKey::Weights(smart_account.clone(), context_rule_id);
+        let mut weights = Self::weights(&e, context_rule_id, smart_account);
+        weights.set(signer, weight);
+        e.storage().persistent().set(&key, &weights);
+    }
+
+    fn weights(e: &Env, context_rule_id: u32, smart_account: Address) -> Map<Signer, u32> {
+        let key = DataKey::Weights(smart_account, context_rule_id);
+        e.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(e))
+    }
+
+    /// Returns the installed threshold, or `None` if the policy has not
+    /// been installed for this context rule.
+    fn threshold(e: &Env, context_rule_id: u32, smart_account: Address) -> Option<u32> {
+        let key = DataKey::Threshold(smart_account, context_rule_id);
+        e.storage().persistent().get(&key)
+    }
+}